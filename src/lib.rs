@@ -1,4 +1,5 @@
 #![doc = include_str!("../README.md")]
+use std::collections::HashMap;
 use std::mem;
 
 use convert_case::{Case, Casing};
@@ -6,8 +7,9 @@ use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::{Group, Punct, Spacing, TokenStream, TokenTree};
 use quote::{format_ident, quote};
 use syn::{
-    braced, bracketed, parse::Parse, parse_macro_input, parse_quote, token::Bracket, FnArg, Ident,
-    ImplItem, ItemImpl, ItemTrait, Meta, Token, TraitItem, Type, Visibility,
+    braced, bracketed, parse::Parse, parse_macro_input, parse_quote, punctuated::Punctuated,
+    token::Bracket, Attribute, FnArg, Ident, ImplItem, ItemImpl, ItemTrait, Meta, Token,
+    TraitItem, Type, Visibility,
 };
 
 /// Replace all mentions of `crate` with `$crate`.
@@ -34,6 +36,208 @@ fn decratify(tokens: &mut TokenStream) {
     *tokens = result.into_iter().collect()
 }
 
+/// One entry in `#[trait_deref(..)]`'s argument list: either the bare macro name or one of
+/// the reserved options (`erase = ErasedName`, `prefer_defaults`, `forward_supertraits`).
+enum TraitDerefArg {
+    MacroName(Ident),
+    Erase(Ident),
+    PreferDefaults,
+    ForwardSupertraits,
+}
+
+impl Parse for TraitDerefArg {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            if ident == "erase" {
+                Ok(TraitDerefArg::Erase(input.parse()?))
+            } else {
+                Err(syn::Error::new_spanned(
+                    ident,
+                    "unknown `trait_deref` option, expected `erase`.",
+                ))
+            }
+        } else if ident == "prefer_defaults" {
+            Ok(TraitDerefArg::PreferDefaults)
+        } else if ident == "forward_supertraits" {
+            Ok(TraitDerefArg::ForwardSupertraits)
+        } else {
+            Ok(TraitDerefArg::MacroName(ident))
+        }
+    }
+}
+
+/// Replaces `Self::#assoc` in `ty` with its `#[erase_as(..)]` type where it appears bare, behind a
+/// reference, in a tuple, or nested in a generic type argument (`Vec<Self::Item>`, `Option<Self::Item>`, ..).
+/// Occurrences in other positions (slices, arrays, pointers, `impl`/`dyn` bounds, ..) are left unchanged.
+fn substitute_self_assoc(ty: &mut Type, map: &HashMap<Ident, Type>) {
+    if let Type::Path(path) = &*ty {
+        if path.qself.is_none() && path.path.segments.len() == 2 {
+            let mut segments = path.path.segments.iter();
+            let first = segments.next().unwrap();
+            let second = segments.next().unwrap();
+            if first.ident == "Self" {
+                if let Some(replacement) = map.get(&second.ident) {
+                    *ty = replacement.clone();
+                    return;
+                }
+            }
+        }
+    }
+    match ty {
+        Type::Path(path) => {
+            for segment in &mut path.path.segments {
+                if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    for arg in &mut args.args {
+                        if let syn::GenericArgument::Type(ty) = arg {
+                            substitute_self_assoc(ty, map);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Reference(r) => substitute_self_assoc(&mut r.elem, map),
+        Type::Tuple(t) => t.elems.iter_mut().for_each(|ty| substitute_self_assoc(ty, map)),
+        _ => (),
+    }
+}
+
+/// Applies [`substitute_self_assoc`] to every input and the return type of `sig`.
+fn substitute_self_assoc_in_sig(sig: &mut syn::Signature, map: &HashMap<Ident, Type>) {
+    for input in &mut sig.inputs {
+        if let FnArg::Typed(typed) = input {
+            substitute_self_assoc(&mut typed.ty, map);
+        }
+    }
+    if let syn::ReturnType::Type(_, ty) = &mut sig.output {
+        substitute_self_assoc(ty, map);
+    }
+}
+
+/// Builds the `#[trait_deref(erase = ..)]` companion: a dyn-compatible trait containing the
+/// plain-receiver, non-generic, non-`#[rc]` methods (with associated types erased via
+/// `#[erase_as(..)]`), a blanket impl bridging it to `trait_ident`, and `#[rc]`-method bridges
+/// reachable directly off `Rc<dyn ..>`/`Arc<dyn ..>` via `self: Rc<Self>`/`self: Arc<Self>`
+/// receivers (the only way to reach them without knowing the concrete erased type).
+fn build_erasure(
+    trait_ident: &Ident,
+    vis: &Visibility,
+    item_trait: &ItemTrait,
+    erase_name: &Ident,
+) -> syn::Result<TokenStream> {
+    let mut erase_as = HashMap::new();
+    for item in &item_trait.items {
+        if let TraitItem::Type(t) = item {
+            for attr in &t.attrs {
+                if attr.path().is_ident("erase_as") {
+                    erase_as.insert(t.ident.clone(), attr.parse_args::<Type>()?);
+                }
+            }
+        }
+    }
+
+    let mut erased_sigs = Vec::new();
+    let mut blanket_items = Vec::new();
+
+    for item in &item_trait.items {
+        let TraitItem::Fn(f) = item else { continue };
+
+        if f.attrs.iter().any(|x| x.path().is_ident("rc")) {
+            let mut sig = f.sig.clone();
+            substitute_self_assoc_in_sig(&mut sig, &erase_as);
+            let ident = sig.ident.clone();
+            let mut inputs = sig.inputs.iter();
+            if inputs.next().is_none() || inputs.next().is_none() {
+                // `#[trait_deref(erase = ..)]` can be used without ever invoking the
+                // generated `impl_*!` macro, so this can't rely on `impl_trait` to report it.
+                return Err(syn::Error::new_spanned(
+                    sig,
+                    "`#[rc]` methods need a `this` value plus a getter closure as their first two parameters.",
+                ));
+            }
+            let rest: Vec<_> = sig.inputs.iter().skip(2).cloned().collect();
+            let rest_names: Vec<_> = rest
+                .iter()
+                .filter_map(|x| match x {
+                    FnArg::Typed(a) => Some(a.pat.clone()),
+                    FnArg::Receiver(_) => None,
+                })
+                .collect();
+            let ret = &sig.output;
+            let arc_ident = format_ident!("{ident}_arc");
+
+            erased_sigs.push(quote! {
+                fn #ident(self: ::std::rc::Rc<Self>, #(#rest),*) #ret;
+                fn #arc_ident(self: ::std::sync::Arc<Self>, #(#rest),*) #ret;
+            });
+            blanket_items.push(quote! {
+                fn #ident(self: ::std::rc::Rc<Self>, #(#rest),*) #ret {
+                    #trait_ident::#ident(self, ::std::rc::Rc::as_ref, #(#rest_names),*)
+                }
+                fn #arc_ident(self: ::std::sync::Arc<Self>, #(#rest),*) #ret {
+                    #trait_ident::#ident(self, ::std::sync::Arc::as_ref, #(#rest_names),*)
+                }
+            });
+            continue;
+        }
+
+        if !f.sig.generics.params.is_empty() {
+            continue;
+        }
+        let Some(recv) = f.sig.receiver() else {
+            continue;
+        };
+        if recv.colon_token.is_some() {
+            continue;
+        }
+        if recv.reference.is_none() {
+            // A plain by-value `self` can't be called through `Rc<dyn ..>`/`Arc<dyn ..>` (there's
+            // no way to move out of one), so it can't be declared on the erased companion trait.
+            return Err(syn::Error::new_spanned(
+                recv,
+                "a plain by-value `self` receiver cannot be part of the erased companion trait; mark it `#[rc]` or implement it manually.",
+            ));
+        }
+
+        let mut sig = f.sig.clone();
+        substitute_self_assoc_in_sig(&mut sig, &erase_as);
+        let ident = sig.ident.clone();
+        let names: Vec<_> = sig
+            .inputs
+            .iter()
+            .filter_map(|x| match x {
+                FnArg::Receiver(_) => None,
+                FnArg::Typed(x) => Some(x.pat.clone()),
+            })
+            .collect();
+
+        erased_sigs.push(quote! { #sig; });
+        blanket_items.push(quote! {
+            #sig {
+                #trait_ident::#ident(self, #(#names),*)
+            }
+        });
+    }
+
+    let bound = if erase_as.is_empty() {
+        quote! { #trait_ident }
+    } else {
+        let assoc_bounds = erase_as.iter().map(|(ident, ty)| quote! { #ident = #ty });
+        quote! { #trait_ident<#(#assoc_bounds),*> }
+    };
+
+    Ok(quote! {
+        #vis trait #erase_name {
+            #(#erased_sigs)*
+        }
+
+        impl<__EraseEachTo: #bound> #erase_name for __EraseEachTo {
+            #(#blanket_items)*
+        }
+    })
+}
+
 /// Generates a macro that fills missing trait items in an `impl` block by inheriting from one of its fields.
 ///
 /// # Syntax
@@ -108,6 +312,93 @@ fn decratify(tokens: &mut TokenStream) {
 ///
 /// The trait bound on `RC` can be tailored to your specific needs, for instance `Into<Arc<dyn ErasedMyTrait>>`.
 ///
+/// # Erasing `#[rc]` traits
+///
+/// Writing `ErasedMyTrait` and its bridging impls by hand, as in the previous section, can be
+/// generated instead with `#[trait_deref(erase = ErasedMyTrait)]`:
+///
+/// ```
+/// # use trait_deref::trait_deref;
+/// #[trait_deref(erase = ErasedMyTrait)]
+/// pub trait MyTrait {
+///     #[erase_as(i32)]
+///     type Item;
+///
+///     fn get_name(&self) -> &str;
+///
+///     #[rc]
+///     fn get<RC: Clone>(this: RC, get: impl Fn(&RC) -> &Self) -> Self::Item;
+/// }
+/// ```
+///
+/// This additionally emits an object-safe `ErasedMyTrait` containing the plain-receiver,
+/// non-generic, non-`#[rc]` methods (`get_name` here), with associated types replaced by the
+/// concrete type named in their `#[erase_as(..)]` attribute; a blanket
+/// `impl<T: MyTrait<Item = i32>> ErasedMyTrait for T`; and, for every `#[rc]` method, a pair of
+/// bridging methods reachable directly off `Rc<dyn ErasedMyTrait>`/`Arc<dyn ErasedMyTrait>`
+/// (named `get`/`get_arc` for a method named `get`) that supply `Rc::as_ref`/`Arc::as_ref` as
+/// the getter.
+///
+/// # Multiple Fields
+///
+/// `@[..]` can list more than one field, letting a type composed of several trait
+/// implementors route different items to different fields. Items default to the
+/// first field listed; tag an item `#[from(field)]` in the trait definition to send
+/// it somewhere else.
+///
+/// ```
+/// # /*
+/// impl_card! {
+///     @[base: T, pricing: P]
+///     impl<T: Card, P: Card> Card for CardPricingExtension<T, P> {
+///         // `get_cost` is tagged `#[from(pricing)]` on the `Card` trait, so it
+///         // forwards to `self.pricing.get_cost()` instead of `self.base`.
+///     }
+/// }
+/// # */
+/// ```
+///
+/// # Preferring Defaults
+///
+/// By default, a trait's default function and const bodies are not used: a missing item is
+/// forwarded to a field even if the trait supplies a default (the one exception is a method whose
+/// receiver can't be forwarded, see the rules below). `#[trait_deref(prefer_defaults)]`
+/// flips that: any item with a default that's absent from the `impl` block is left out of the generated
+/// impl, so the trait's own default applies instead of a forward. Items without a default still forward
+/// as usual, so only genuinely default-free items pay the forwarding cost.
+///
+/// ```
+/// # use trait_deref::trait_deref;
+/// #[trait_deref(prefer_defaults)]
+/// pub trait MyTrait {
+///     fn get_name(&self) -> &str;
+///
+///     // Not overridden and not forwarded: composed types just inherit this default.
+///     fn greeting(&self) -> String {
+///         format!("Hello, {}!", self.get_name())
+///     }
+/// }
+/// ```
+///
+/// # Forwarding Supertraits
+///
+/// `#[trait_deref(forward_supertraits)]` makes the generated `impl_*!` macro also fill in
+/// impls for a curated set of std supertraits (`Display`, `Debug`, `AsRef<T>`, `Deref`) the
+/// trait declares, forwarding each to the default inheritance field. Lifetime bounds (`'static`, ..)
+/// and auto traits (`Send`, `Sync`, `Unpin`) are skipped, since the compiler derives those on its
+/// own and they can't be satisfied by a normal `impl` block. Any other supertrait is reported with
+/// a `compile_error!` asking for a manual impl instead.
+///
+/// ```
+/// # use trait_deref::trait_deref;
+/// use std::fmt::Display;
+///
+/// #[trait_deref(forward_supertraits)]
+/// pub trait MyTrait: Display {
+///     fn get_name(&self) -> &str;
+/// }
+/// ```
+///
 /// # import
 ///
 /// The macro cannot find the path of items automatically, so add them manually with `#[import]`:
@@ -126,8 +417,26 @@ fn decratify(tokens: &mut TokenStream) {
 /// # Rules
 ///
 /// * The macro does not rewrite the trait, except removing attributes specific to this macro.
-/// * Default function or const implementations will not be used.
-/// * Receivers like `self: Box<Self>` is not supported and such items will be ignored.
+/// * Default function or const implementations will not be used (except for an unsupported receiver,
+///   see below), unless `#[trait_deref(prefer_defaults)]`
+///   is set, in which case an item with a default that's missing from the `impl` block is left out of
+///   the generated impl entirely (so the trait's default applies) instead of being forwarded.
+/// * `self: Box<Self>` receivers are forwarded by unwrapping and re-boxing the field.
+/// * `self: Pin<&mut Self>` receivers are forwarded only if the method is marked `#[pin]`,
+///   which vouches that the field is structurally pinned; the forward then projects into
+///   it with `Pin::new_unchecked` inside an `unsafe` block.
+/// * Any other unsupported receiver (including `self: Pin<&mut Self>` missing `#[pin]`)
+///   raises a `compile_error!` pointing at the offending item instead of being silently
+///   dropped, unless the trait already supplies a default body for it, in which case the
+///   item is left out of the generated impl so the default applies.
+/// * When `@[..]` lists multiple fields, an item forwards to the first one unless tagged
+///   `#[from(field)]`; a `#[from]` naming a field that isn't listed is a `compile_error!`.
+/// * `#[trait_deref(erase = ErasedName)]` generates an object-safe `ErasedName` as described
+///   above; `#[erase_as(..)]` on an associated type supplies the concrete type it's erased to.
+/// * `#[trait_deref(forward_supertraits)]` fills in impls of `Display`, `Debug`, `AsRef<T>`
+///   and `Deref` supertraits by forwarding to the default field; lifetime bounds and auto
+///   traits (`Send`, `Sync`, `Unpin`) are skipped, and any other supertrait raises a
+///   `compile_error!` asking for a manual impl.
 #[proc_macro_attribute]
 pub fn trait_deref(args: TokenStream1, trait_block: TokenStream1) -> TokenStream1 {
     let mut item_trait = parse_macro_input!(trait_block as ItemTrait);
@@ -150,18 +459,51 @@ pub fn trait_deref(args: TokenStream1, trait_block: TokenStream1) -> TokenStream
         }
     });
     for item in &mut trait_out.items {
-        if let TraitItem::Fn(f) = item {
-            f.attrs.retain_mut(|x| !x.path().is_ident("rc"));
+        match item {
+            TraitItem::Fn(f) => f
+                .attrs
+                .retain_mut(|x| !is_trait_deref_attr(x, &["rc", "pin", "from"])),
+            TraitItem::Const(c) => c.attrs.retain_mut(|x| !is_trait_deref_attr(x, &["from"])),
+            TraitItem::Type(t) => t
+                .attrs
+                .retain_mut(|x| !is_trait_deref_attr(x, &["from", "erase_as"])),
+            _ => (),
         }
     }
 
     let ident = item_trait.ident.clone();
 
-    let name = if let Ok(name) = syn::parse::<Ident>(args) {
-        name
-    } else {
-        let ident = ident.to_string().to_case(Case::Snake);
-        format_ident!("impl_{ident}")
+    let parse_args = Punctuated::<TraitDerefArg, Token![,]>::parse_terminated;
+    let args = parse_macro_input!(args with parse_args);
+
+    let name = args
+        .iter()
+        .find_map(|arg| match arg {
+            TraitDerefArg::MacroName(name) => Some(name.clone()),
+            _ => None,
+        })
+        .unwrap_or_else(|| {
+            let ident = ident.to_string().to_case(Case::Snake);
+            format_ident!("impl_{ident}")
+        });
+
+    let erase_name = args.iter().find_map(|arg| match arg {
+        TraitDerefArg::Erase(name) => Some(name.clone()),
+        _ => None,
+    });
+
+    let prefer_defaults = args
+        .iter()
+        .any(|arg| matches!(arg, TraitDerefArg::PreferDefaults));
+
+    let forward_supertraits = args
+        .iter()
+        .any(|arg| matches!(arg, TraitDerefArg::ForwardSupertraits));
+
+    let erasure = match &erase_name {
+        Some(erase_name) => build_erasure(&ident, &item_trait.vis, &item_trait, erase_name)
+            .unwrap_or_else(|err| err.to_compile_error()),
+        None => quote! {},
     };
 
     let macro_export = if matches!(&item_trait.vis, Visibility::Inherited) {
@@ -170,23 +512,35 @@ pub fn trait_deref(args: TokenStream1, trait_block: TokenStream1) -> TokenStream
         quote! {#[macro_export]}
     };
 
-    for item in &mut item_trait.items {
-        match item {
-            TraitItem::Const(item) => {
-                item.default = None;
-            }
-            TraitItem::Fn(item) => {
-                item.default = None;
-            }
-            TraitItem::Type(item) => {
-                item.default = None;
+    if !prefer_defaults {
+        for item in &mut item_trait.items {
+            match item {
+                TraitItem::Const(item) => {
+                    item.default = None;
+                }
+                TraitItem::Fn(item) => {
+                    if item.default.is_some() {
+                        // Recorded so `impl_trait` can tell, even after the default below is
+                        // stripped, that a method with an unsupported receiver already has a
+                        // hand-written default body and shouldn't be forced into an error.
+                        item.attrs.push(parse_quote!(#[had_default]));
+                    }
+                    item.default = None;
+                }
+                TraitItem::Type(item) => {
+                    item.default = None;
+                }
+                _ => (),
             }
-            _ => (),
         }
     }
 
+    if forward_supertraits {
+        item_trait.attrs.push(parse_quote!(#[forward_supertraits]));
+    }
+
     let doc = format!(
-        "Implement trait [`{ident}`]. Methods not specified will be forwarded to a field's implementation.\n# Syntax\n```\n# /*\n{name}!{{\n    @[field: T]\n    impl<T: {ident}> {ident} for MyType<T> {{\n        ..\n    }}\n}}\n# */\n```"
+        "Implement trait [`{ident}`]. Methods not specified will be forwarded to a field's implementation (or another listed field, via `#[from(field)]`).\n# Syntax\n```\n# /*\n{name}!{{\n    @[field: T, ..]\n    impl<T: {ident}> {ident} for MyType<T> {{\n        ..\n    }}\n}}\n# */\n```"
     );
 
     let mut trait_in = quote! {#item_trait};
@@ -195,6 +549,8 @@ pub fn trait_deref(args: TokenStream1, trait_block: TokenStream1) -> TokenStream
     quote! {
         #trait_out
 
+        #erasure
+
         #[allow(unused_macros)]
         #[doc = #doc]
         #macro_export
@@ -220,10 +576,15 @@ struct ImplTraitInput {
 struct ImplBlock {
     pub at_token: Token![@],
     pub bracket: Bracket,
+    pub fields: Punctuated<FieldEntry, Token![,]>,
+    pub item_impl: ItemImpl,
+}
+
+/// One `field: T` entry in the `@[..]` header, naming an inheritance source.
+struct FieldEntry {
     pub field: Ident,
     pub colon_token: Token![:],
     pub ty: Type,
-    pub item_impl: ItemImpl,
 }
 
 impl Parse for ImplTraitInput {
@@ -245,14 +606,215 @@ impl Parse for ImplBlock {
         Ok(ImplBlock {
             at_token: input.parse()?,
             bracket: bracketed!(content in input),
-            field: content.parse()?,
-            colon_token: content.parse()?,
-            ty: content.parse()?,
+            fields: Punctuated::parse_terminated(&content)?,
             item_impl: input.parse()?,
         })
     }
 }
 
+impl Parse for FieldEntry {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(FieldEntry {
+            field: input.parse()?,
+            colon_token: input.parse()?,
+            ty: input.parse()?,
+        })
+    }
+}
+
+/// Returns `true` if `attr` is one of `trait_deref`'s own item attributes (`#[rc]`, `#[pin]`, `#[from(..)]`, ..).
+fn is_trait_deref_attr(attr: &Attribute, names: &[&str]) -> bool {
+    names.iter().any(|name| attr.path().is_ident(name))
+}
+
+/// Picks which `@[..]` field a trait item should forward to: the one named by an
+/// `#[from(field)]` attribute on the item, or the first listed field otherwise.
+fn select_field<'a>(
+    attrs: &[Attribute],
+    fields: &'a Punctuated<FieldEntry, Token![,]>,
+) -> syn::Result<&'a FieldEntry> {
+    for attr in attrs {
+        if attr.path().is_ident("from") {
+            let wanted: Ident = attr.parse_args()?;
+            return fields.iter().find(|f| f.field == wanted).ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &wanted,
+                    format!("no field named `{wanted}` in `@[..]` to forward to via `#[from({wanted})]`."),
+                )
+            });
+        }
+    }
+    fields.first().ok_or_else(|| {
+        syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`@[..]` must list at least one field.",
+        )
+    })
+}
+
+/// Returns `true` if `ty` is `Self`.
+fn is_self_type(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("Self"))
+}
+
+/// Returns `true` if `ty` is `#wrapper<Self>`, e.g. `Box<Self>`.
+fn is_wrapped_self(ty: &Type, wrapper: &str) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return false;
+    };
+    if segment.ident != wrapper {
+        return false;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+    matches!(args.args.first(), Some(syn::GenericArgument::Type(ty)) if is_self_type(ty))
+}
+
+/// Returns `true` if `ty` is `Pin<&mut Self>`.
+fn is_pinned_mut_self(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return false;
+    };
+    if segment.ident != "Pin" {
+        return false;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+    matches!(
+        args.args.first(),
+        Some(syn::GenericArgument::Type(Type::Reference(r)))
+            if r.mutability.is_some() && is_self_type(&r.elem)
+    )
+}
+
+/// Error for an `#[rc]` method whose signature doesn't provide a `this` value
+/// plus a getter closure as its first two parameters.
+fn rc_signature_error(sig: &syn::Signature) -> ImplItem {
+    ImplItem::Verbatim(
+        syn::Error::new_spanned(
+            sig,
+            "`#[rc]` methods need a `this` value plus a getter closure as their first two parameters.",
+        )
+        .to_compile_error(),
+    )
+}
+
+/// Auto traits that the compiler derives on their own and that can't be satisfied by a normal
+/// `impl` block; a supertrait bound of one of these is not an error, just nothing to forward.
+const AUTO_TRAITS: &[&str] = &["Send", "Sync", "Unpin"];
+
+/// For `#[trait_deref(forward_supertraits)]`, emits `impl` blocks for the curated set of std
+/// supertraits (`Display`, `Debug`, `AsRef<T>`, `Deref`) this crate knows how to forward to the
+/// default inheritance field. Lifetime bounds (e.g. `'static`) and auto traits (`Send`, `Sync`,
+/// `Unpin`) are skipped, since neither is something a user could "implement manually" anyway.
+/// Any other supertrait is reported as a `compile_error!` telling the user it must be
+/// implemented manually.
+fn build_supertrait_impls(
+    supertraits: &Punctuated<syn::TypeParamBound, Token![+]>,
+    impl_block: &ItemImpl,
+    fields: &Punctuated<FieldEntry, Token![,]>,
+) -> Vec<TokenStream> {
+    if supertraits.is_empty() {
+        return Vec::new();
+    }
+
+    let entry = match select_field(&[], fields) {
+        Ok(entry) => entry,
+        Err(err) => return vec![err.to_compile_error()],
+    };
+    let field = &entry.field;
+    let field_ty = &entry.ty;
+
+    let generics = &impl_block.generics;
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let self_ty = &impl_block.self_ty;
+
+    supertraits
+        .iter()
+        .filter_map(|bound| {
+            let syn::TypeParamBound::Trait(trait_bound) = bound else {
+                // Lifetime bounds (`'static`, ..) aren't traits and have nothing to forward.
+                return None;
+            };
+            let Some(segment) = trait_bound.path.segments.last() else {
+                return Some(
+                    syn::Error::new_spanned(
+                        trait_bound,
+                        "this supertrait cannot be forwarded automatically and must be implemented manually.",
+                    )
+                    .to_compile_error(),
+                );
+            };
+            if AUTO_TRAITS.contains(&segment.ident.to_string().as_str()) {
+                // Auto traits are derived by the compiler, not satisfied by an `impl` block.
+                return None;
+            }
+            Some(match segment.ident.to_string().as_str() {
+                "Display" => quote! {
+                    impl #impl_generics ::std::fmt::Display for #self_ty #where_clause {
+                        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                            ::std::fmt::Display::fmt(&self.#field, f)
+                        }
+                    }
+                },
+                "Debug" => quote! {
+                    impl #impl_generics ::std::fmt::Debug for #self_ty #where_clause {
+                        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                            ::std::fmt::Debug::fmt(&self.#field, f)
+                        }
+                    }
+                },
+                "Deref" => quote! {
+                    impl #impl_generics ::std::ops::Deref for #self_ty #where_clause {
+                        type Target = #field_ty;
+
+                        fn deref(&self) -> &Self::Target {
+                            &self.#field
+                        }
+                    }
+                },
+                "AsRef" => {
+                    let target = match &segment.arguments {
+                        syn::PathArguments::AngleBracketed(args) => args.args.first(),
+                        _ => None,
+                    };
+                    let Some(syn::GenericArgument::Type(target)) = target else {
+                        return Some(
+                            syn::Error::new_spanned(
+                                segment,
+                                "the `AsRef` supertrait needs a concrete target type, e.g. `AsRef<str>`.",
+                            )
+                            .to_compile_error(),
+                        );
+                    };
+                    quote! {
+                        impl #impl_generics ::std::convert::AsRef<#target> for #self_ty #where_clause {
+                            fn as_ref(&self) -> &#target {
+                                ::std::convert::AsRef::as_ref(&self.#field)
+                            }
+                        }
+                    }
+                }
+                other => syn::Error::new_spanned(
+                    segment,
+                    format!(
+                        "the supertrait `{other}` is not one of the std traits `trait_deref` knows how to forward (`Display`, `Debug`, `AsRef`, `Deref`); implement it manually."
+                    ),
+                )
+                .to_compile_error(),
+            })
+        })
+        .collect()
+}
+
 /// Fill missing items in a trait.
 #[doc(hidden)]
 #[proc_macro]
@@ -261,12 +823,17 @@ pub fn impl_trait(tokens: TokenStream1) -> TokenStream1 {
 
     let mut impl_block = input.impl_block.item_impl;
 
-    let field = input.impl_block.field;
-
-    let inner_ty = input.impl_block.ty;
+    let fields = input.impl_block.fields;
 
     let mut extended = Vec::new();
 
+    let forward_supertraits = input
+        .item_trait
+        .attrs
+        .iter()
+        .any(|x| x.path().is_ident("forward_supertraits"));
+    let supertraits = input.item_trait.supertraits.clone();
+
     let trait_name = input.item_trait.ident;
 
     for item in input.item_trait.items {
@@ -275,7 +842,16 @@ pub fn impl_trait(tokens: TokenStream1) -> TokenStream1 {
                 if !impl_block.items.iter().any(|x| match x {
                     ImplItem::Const(v) => v.ident == item.ident,
                     _ => false,
-                }) {
+                }) && item.default.is_none()
+                {
+                    let entry = match select_field(&item.attrs, &fields) {
+                        Ok(entry) => entry,
+                        Err(err) => {
+                            extended.push(ImplItem::Verbatim(err.to_compile_error()));
+                            continue;
+                        }
+                    };
+                    let inner_ty = &entry.ty;
                     let ident = item.ident;
                     let ty = item.ty;
                     extended.push(parse_quote!(
@@ -288,16 +864,31 @@ pub fn impl_trait(tokens: TokenStream1) -> TokenStream1 {
                 if !impl_block.items.iter().any(|x| match x {
                     ImplItem::Fn(v) => v.sig.ident == item.sig.ident,
                     _ => false,
-                }) {
+                }) && item.default.is_none()
+                {
+                    let entry = match select_field(&item.attrs, &fields) {
+                        Ok(entry) => entry,
+                        Err(err) => {
+                            extended.push(ImplItem::Verbatim(err.to_compile_error()));
+                            continue;
+                        }
+                    };
+                    let field = &entry.field;
                     let sig = &item.sig;
                     let ident = &sig.ident;
                     let this = match item.sig.inputs.get(0) {
                         Some(FnArg::Typed(arg)) => &arg.pat,
-                        _ => panic!("Expected at least 2 items and no receiver."),
+                        _ => {
+                            extended.push(rc_signature_error(sig));
+                            continue;
+                        }
                     };
                     let func = match item.sig.inputs.get(1) {
                         Some(FnArg::Typed(arg)) => &arg.pat,
-                        _ => panic!("Expected at least 2 items."),
+                        _ => {
+                            extended.push(rc_signature_error(sig));
+                            continue;
+                        }
                     };
                     let rest = item.sig.inputs.iter().skip(2).filter_map(|x| match x {
                         FnArg::Receiver(_) => None,
@@ -314,7 +905,16 @@ pub fn impl_trait(tokens: TokenStream1) -> TokenStream1 {
                 if !impl_block.items.iter().any(|x| match x {
                     ImplItem::Fn(v) => v.sig.ident == item.sig.ident,
                     _ => false,
-                }) {
+                }) && item.default.is_none()
+                {
+                    let entry = match select_field(&item.attrs, &fields) {
+                        Ok(entry) => entry,
+                        Err(err) => {
+                            extended.push(ImplItem::Verbatim(err.to_compile_error()));
+                            continue;
+                        }
+                    };
+                    let field = &entry.field;
                     let sig = item.sig;
                     let ident = &sig.ident;
                     let names = sig.inputs.iter().filter_map(|x| match x {
@@ -323,18 +923,54 @@ pub fn impl_trait(tokens: TokenStream1) -> TokenStream1 {
                     });
                     let recv = match sig.receiver() {
                         None => continue,
-                        Some(recv) => {
-                            if recv.colon_token.is_some() {
-                                continue;
-                            }
-                            if recv.reference.is_none() {
-                                quote! {}
-                            } else if recv.mutability.is_some() {
-                                quote! {&mut}
-                            } else {
-                                quote! {&}
+                        Some(recv) => recv,
+                    };
+                    if recv.colon_token.is_some() {
+                        let had_default = item.attrs.iter().any(|x| x.path().is_ident("had_default"));
+                        if is_wrapped_self(&recv.ty, "Box") {
+                            extended.push(parse_quote!(
+                                #sig {
+                                    #trait_name::#ident(Box::new((*self).#field), #(#names),*)
+                                }
+                            ));
+                        } else if is_pinned_mut_self(&recv.ty) {
+                            if item.attrs.iter().any(|x| x.path().is_ident("pin")) {
+                                extended.push(parse_quote!(
+                                    #sig {
+                                        unsafe {
+                                            #trait_name::#ident(::core::pin::Pin::new_unchecked(&mut self.get_unchecked_mut().#field), #(#names),*)
+                                        }
+                                    }
+                                ));
+                            } else if !had_default {
+                                extended.push(ImplItem::Verbatim(
+                                    syn::Error::new_spanned(
+                                        recv,
+                                        "forwarding a `Pin<&mut Self>` receiver projects into the field unsafely; mark this trait item `#[pin]` to vouch that the field is structurally pinned, or implement it manually.",
+                                    )
+                                    .to_compile_error(),
+                                ));
                             }
+                            // else: the trait already supplies a default body for this method
+                            // (stripped from `trait_in` but still present on the public trait);
+                            // leave it alone instead of forcing an error.
+                        } else if !had_default {
+                            extended.push(ImplItem::Verbatim(
+                                syn::Error::new_spanned(
+                                    recv,
+                                    "this receiver is not supported by `#[trait_deref]` and must be implemented manually.",
+                                )
+                                .to_compile_error(),
+                            ));
                         }
+                        continue;
+                    }
+                    let recv = if recv.reference.is_none() {
+                        quote! {}
+                    } else if recv.mutability.is_some() {
+                        quote! {&mut}
+                    } else {
+                        quote! {&}
                     };
                     extended.push(parse_quote!(
                         #sig {
@@ -347,7 +983,16 @@ pub fn impl_trait(tokens: TokenStream1) -> TokenStream1 {
                 if !impl_block.items.iter().any(|x| match x {
                     ImplItem::Type(v) => v.ident == item.ident,
                     _ => false,
-                }) {
+                }) && item.default.is_none()
+                {
+                    let entry = match select_field(&item.attrs, &fields) {
+                        Ok(entry) => entry,
+                        Err(err) => {
+                            extended.push(ImplItem::Verbatim(err.to_compile_error()));
+                            continue;
+                        }
+                    };
+                    let inner_ty = &entry.ty;
                     let ident = item.ident;
                     extended.push(parse_quote!(
                         type #ident = #inner_ty::#ident;
@@ -360,5 +1005,15 @@ pub fn impl_trait(tokens: TokenStream1) -> TokenStream1 {
 
     impl_block.items.extend(extended);
 
-    quote! {#impl_block}.into()
+    let supertrait_impls = if forward_supertraits {
+        build_supertrait_impls(&supertraits, &impl_block, &fields)
+    } else {
+        Vec::new()
+    };
+
+    quote! {
+        #impl_block
+        #(#supertrait_impls)*
+    }
+    .into()
 }
@@ -0,0 +1,68 @@
+use std::pin::Pin;
+
+use trait_deref::trait_deref;
+
+#[trait_deref(inherit_wrapper)]
+trait Wrapper {
+    fn get_name(&self) -> &str;
+
+    fn into_name(self: Box<Self>) -> String;
+
+    // `#[pin]` vouches that `Ext::item` is structurally pinned, so the generated forward may
+    // project into it with `Pin::new_unchecked`. Omitting it on a `Pin<&mut Self>` method is a
+    // `compile_error!` instead of a silent unsafe projection.
+    #[pin]
+    fn set_tag(self: Pin<&mut Self>, tag: i32);
+}
+
+struct Base {
+    tag: i32,
+}
+
+impl Wrapper for Base {
+    fn get_name(&self) -> &str {
+        "Base"
+    }
+
+    fn into_name(self: Box<Self>) -> String {
+        format!("Base({})", self.tag)
+    }
+
+    fn set_tag(self: Pin<&mut Self>, tag: i32) {
+        // Safe: `tag` is a plain field, not structurally pinned.
+        unsafe {
+            self.get_unchecked_mut().tag = tag;
+        }
+    }
+}
+
+struct Ext {
+    item: Base,
+}
+
+inherit_wrapper! {
+    @[item: Base]
+    impl Wrapper for Ext {
+        fn get_name(&self) -> &str {
+            "Ext"
+        }
+    }
+}
+
+#[test]
+fn main() {
+    // `self: Box<Self>` receivers forward by unwrapping and re-boxing the field.
+    let boxed: Box<Ext> = Box::new(Ext {
+        item: Base { tag: 1 },
+    });
+    assert_eq!(boxed.into_name(), "Base(1)");
+
+    // `self: Pin<&mut Self>` receivers forward only when marked `#[pin]`, projecting into the
+    // field with `Pin::new_unchecked`.
+    let mut ext = Ext {
+        item: Base { tag: 0 },
+    };
+    Pin::new(&mut ext).set_tag(7);
+    assert_eq!(ext.item.tag, 7);
+    assert_eq!(ext.get_name(), "Ext");
+}
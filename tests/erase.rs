@@ -0,0 +1,49 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use trait_deref::trait_deref;
+
+#[trait_deref(erase = ErasedMyTrait)]
+trait MyTrait {
+    #[erase_as(i32)]
+    type Item;
+
+    fn get_name(&self) -> &str;
+
+    // `Self::Item` nested in a generic argument, not just bare or behind a reference.
+    fn items(&self) -> Vec<Self::Item>;
+
+    #[rc]
+    fn get<RC: Clone>(this: RC, get: impl Fn(&RC) -> &Self) -> Self::Item;
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Base(i32);
+
+impl MyTrait for Base {
+    type Item = i32;
+
+    fn get_name(&self) -> &str {
+        "Base"
+    }
+
+    fn items(&self) -> Vec<Self::Item> {
+        vec![self.0]
+    }
+
+    fn get<RC: Clone>(this: RC, get: impl Fn(&RC) -> &Self) -> Self::Item {
+        get(&this).0
+    }
+}
+
+#[test]
+fn main() {
+    let rc: Rc<dyn ErasedMyTrait> = Rc::new(Base(4));
+    assert_eq!(rc.get_name(), "Base");
+    assert_eq!(rc.items(), vec![4]);
+    assert_eq!(rc.get(), 4);
+
+    let arc: Arc<dyn ErasedMyTrait> = Arc::new(Base(5));
+    assert_eq!(arc.get_name(), "Base");
+    assert_eq!(arc.get_arc(), 5);
+}
@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+use trait_deref::trait_deref;
+
+#[trait_deref(inherit_named, forward_supertraits)]
+trait Named: Display + Send + 'static {
+    fn get_name(&self) -> &str;
+}
+
+struct Base;
+
+impl Display for Base {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Base")
+    }
+}
+
+impl Named for Base {
+    fn get_name(&self) -> &str {
+        "Base"
+    }
+}
+
+struct Ext<T: Named> {
+    item: T,
+}
+
+inherit_named! {
+    @[item: T]
+    impl<T: Named> Named for Ext<T> {
+        fn get_name(&self) -> &str {
+            "Ext"
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let ext = Ext { item: Base };
+    assert_eq!(ext.get_name(), "Ext");
+    // `Display` is a supertrait of `Named`; `forward_supertraits` generates an impl that
+    // forwards to `self.item` without it being hand-written. The `Send` and `'static` bounds
+    // are also supertraits of `Named`, but `forward_supertraits` skips them instead of
+    // erroring, since neither is something a hand-written `impl` block could satisfy anyway.
+    assert_eq!(ext.to_string(), "Base");
+}
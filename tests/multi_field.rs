@@ -0,0 +1,72 @@
+use trait_deref::trait_deref;
+
+#[trait_deref(inherit_combo)]
+trait Combo {
+    const A: i32;
+
+    #[from(secondary)]
+    const B: i32;
+
+    fn get_a(&self) -> i32;
+
+    #[from(secondary)]
+    fn get_b(&self) -> i32;
+}
+
+#[derive(Clone, Copy)]
+struct Base;
+
+impl Combo for Base {
+    const A: i32 = 1;
+    const B: i32 = 10;
+
+    fn get_a(&self) -> i32 {
+        1
+    }
+
+    fn get_b(&self) -> i32 {
+        10
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Other;
+
+impl Combo for Other {
+    const A: i32 = 2;
+    const B: i32 = 20;
+
+    fn get_a(&self) -> i32 {
+        2
+    }
+
+    fn get_b(&self) -> i32 {
+        20
+    }
+}
+
+struct Merged {
+    primary: Base,
+    secondary: Other,
+}
+
+inherit_combo! {
+    @[primary: Base, secondary: Other]
+    impl Combo for Merged {}
+}
+
+#[test]
+fn main() {
+    let m = Merged {
+        primary: Base,
+        secondary: Other,
+    };
+
+    // Defaults to the first field, `primary`.
+    assert_eq!(Merged::A, 1);
+    assert_eq!(m.get_a(), 1);
+
+    // Routed to `secondary` via `#[from(secondary)]`.
+    assert_eq!(Merged::B, 20);
+    assert_eq!(m.get_b(), 20);
+}
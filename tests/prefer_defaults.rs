@@ -0,0 +1,42 @@
+use trait_deref::trait_deref;
+
+#[trait_deref(inherit_greeter, prefer_defaults)]
+trait Greeter {
+    fn get_name(&self) -> &str;
+
+    fn greeting(&self) -> String {
+        format!("Hello, {}!", self.get_name())
+    }
+}
+
+struct Base;
+
+impl Greeter for Base {
+    fn get_name(&self) -> &str {
+        "Base"
+    }
+}
+
+struct Ext<T: Greeter> {
+    item: T,
+}
+
+inherit_greeter! {
+    @[item: T]
+    impl<T: Greeter> Greeter for Ext<T> {
+        fn get_name(&self) -> &str {
+            "Ext"
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let base = Base;
+    assert_eq!(base.greeting(), "Hello, Base!");
+
+    // `greeting` is never overridden for `Ext`, and with `prefer_defaults` it's not forwarded
+    // to `self.item` either: `Greeter::greeting`'s own default body runs, using `Ext::get_name`.
+    let ext = Ext { item: Base };
+    assert_eq!(ext.greeting(), "Hello, Ext!");
+}